@@ -3,15 +3,64 @@ use base64::{alphabet, engine, Engine as _};
 use nom::branch::alt;
 use nom::bytes::complete::{is_not, tag, tag_no_case, take_while, take_while_m_n};
 use nom::character::complete::{char, digit1, one_of};
-use nom::combinator::{cut, map, map_res, opt, recognize, value, verify};
-use nom::error::{Error, ParseError};
+use nom::combinator::{cut, map, map_res, not, opt, peek, recognize, value, verify};
+use nom::error::{context, convert_error, ContextError, Error, FromExternalError, ParseError, VerboseError};
 use nom::multi::many0;
-use nom::sequence::{delimited, pair, tuple};
-use nom::IResult;
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::{Err, IResult};
 use nom::ParseTo;
-use time::{Date, Month};
+use rust_decimal::Decimal;
+use time::{Date, Month, OffsetDateTime, Time, UtcOffset};
+
+/// Why a literal that has the right *shape* still fails to build a value.
+///
+/// These are the external errors the combinators raise through
+/// [`FromExternalError`]. An error type whose `FromExternalError` impl keeps
+/// the value can attach it to the position where it occurred; nom's own
+/// [`Error`] and [`VerboseError`] drop it and record only the `MapRes` kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LitParseError {
+    /// A `binary'…'` body that is not valid base64url, with the offending text.
+    InvalidBase64(String),
+    /// A date/time component out of range (month, day, hour, leap second, …).
+    InvalidDate(String),
+    /// A digit run that does not fit its backing integer.
+    IntegerOverflow(String),
+}
+
+impl std::fmt::Display for LitParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LitParseError::InvalidBase64(s) => write!(f, "invalid base64url: {s:?}"),
+            LitParseError::InvalidDate(s) => write!(f, "invalid date/time component: {s:?}"),
+            LitParseError::IntegerOverflow(s) => write!(f, "integer out of range: {s:?}"),
+        }
+    }
+}
+
+impl std::error::Error for LitParseError {}
+
+/// Shorthand for the error bounds [`literal`] needs to run every combinator in
+/// this module: it may be the fast [`Error`]`<&str>` or a context-recording
+/// [`VerboseError`],
+/// and must be able to absorb a [`LitParseError`] and a [`DurationError`].
+pub trait LiteralError<'a>:
+    ParseError<&'a str>
+    + ContextError<&'a str>
+    + FromExternalError<&'a str, LitParseError>
+    + FromExternalError<&'a str, DurationError>
+{
+}
+
+impl<'a, E> LiteralError<'a> for E where
+    E: ParseError<&'a str>
+        + ContextError<&'a str>
+        + FromExternalError<&'a str, LitParseError>
+        + FromExternalError<&'a str, DurationError>
+{
+}
 
-pub fn parse_float(inp: &str) -> IResult<&str, f64> {
+pub fn parse_float<'a, E: ParseError<&'a str>>(inp: &'a str) -> IResult<&'a str, f64, E> {
     let (i, float_str) = recognize(verify(
         tuple((
             opt(one_of("+-")),
@@ -25,14 +74,33 @@ pub fn parse_float(inp: &str) -> IResult<&str, f64> {
 
     match float_str.parse_to() {
         Some(f) => Ok((i, f)),
-        None => Err(nom::Err::Error(Error::from_error_kind(
-            i,
-            nom::error::ErrorKind::Float,
-        ))),
+        None => Err(Err::Error(E::from_error_kind(i, nom::error::ErrorKind::Float))),
     }
 }
 
-pub fn parse_string(inp: &str) -> IResult<&str, String> {
+pub fn parse_decimal<'a, E>(inp: &'a str) -> IResult<&'a str, Decimal, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
+    // An optional sign, integer digits and a *required* `.` fraction — no
+    // exponent (those stay with `parse_float`). The trailing `not(e/E)` makes
+    // forms like `123.456e10` fall through to `float` instead of succeeding on
+    // the bare mantissa. `from_str_exact` keeps the written scale, so trailing
+    // zeros survive the round-trip.
+    let parser = recognize(tuple((
+        opt(one_of("+-")),
+        digit1,
+        char('.'),
+        digit1,
+        peek(not(one_of("eE"))),
+    )));
+
+    map_res(parser, |s: &str| {
+        Decimal::from_str_exact(s).map_err(|_| LitParseError::IntegerOverflow(s.to_string()))
+    })(inp)
+}
+
+pub fn parse_string<'a, E: ParseError<&'a str>>(inp: &'a str) -> IResult<&'a str, String, E> {
     let part = alt((
         is_not("'"),
         // Double SQUOTE within a string escapes to a single SQUOTE
@@ -56,7 +124,7 @@ fn is_base64url_char(c: char) -> bool {
     c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '='
 }
 
-pub fn parse_guid(inp: &str) -> IResult<&str, String> {
+pub fn parse_guid<'a, E: ParseError<&'a str>>(inp: &'a str) -> IResult<&'a str, String, E> {
     let (i, guid_str) = recognize(tuple((
         take_while_m_n(8, 8, is_hex_digit),
         char('-'),
@@ -72,51 +140,360 @@ pub fn parse_guid(inp: &str) -> IResult<&str, String> {
     Ok((i, guid_str.to_string()))
 }
 
-pub fn parse_year(inp: &str) -> IResult<&str, i32> {
+pub fn parse_year<'a, E>(inp: &'a str) -> IResult<&'a str, i32, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
     let parser = recognize(tuple((opt(char('-')), take_while_m_n(4, 4, is_digit))));
 
-    map_res(parser, |s: &str| s.parse::<i32>())(inp)
+    map_res(parser, |s: &str| {
+        s.parse::<i32>().map_err(|_| LitParseError::IntegerOverflow(s.to_string()))
+    })(inp)
 }
 
-pub fn parse_month(inp: &str) -> IResult<&str, Month> {
+pub fn parse_month<'a, E>(inp: &'a str) -> IResult<&'a str, Month, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
     let parser = recognize(tuple((one_of("01"), take_while_m_n(1, 1, is_digit))));
 
     map_res(parser, |s: &str| {
-        // We can unwrap this since we parse only 2 digits anyway
+        // We can unwrap the parse since we match exactly 2 digits anyway; only
+        // the range check on the month number can fail.
         let month_num = s.parse::<u8>().unwrap();
-        Month::try_from(month_num)
+        Month::try_from(month_num).map_err(|_| LitParseError::InvalidDate(s.to_string()))
     })(inp)
 }
 
-pub fn parse_day(inp: &str) -> IResult<&str, u8> {
+pub fn parse_day<'a, E>(inp: &'a str) -> IResult<&'a str, u8, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
     let parser = recognize(tuple((one_of("0123"), take_while_m_n(1, 1, is_digit))));
 
-    map_res(parser, |s: &str| s.parse::<u8>())(inp)
+    map_res(parser, |s: &str| {
+        s.parse::<u8>().map_err(|_| LitParseError::InvalidDate(s.to_string()))
+    })(inp)
 }
 
-pub fn parse_date(inp: &str) -> IResult<&str, Date> {
+pub fn parse_date<'a, E>(inp: &'a str) -> IResult<&'a str, Date, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
     // OData `year`s can be negative, conflicting with ISO8601.
     // So we don't use `time::*::parse`
     let parser = tuple((parse_year, char('-'), parse_month, char('-'), parse_day));
 
-    map_res(parser, |(y, _, m, _, d)| Date::from_calendar_date(y, m, d))(inp)
+    map_res(parser, |(y, _, m, _, d)| {
+        // Fails on a day the month does not have (e.g. the 31st of February,
+        // or day `00`); the month is already range-checked by `parse_month`.
+        Date::from_calendar_date(y, m, d)
+            .map_err(|_| LitParseError::InvalidDate(format!("{y:04}-{:02}-{d:02}", u8::from(m))))
+    })(inp)
+}
+
+// Fractional seconds are captured as a raw digit run; OData places no bound on
+// its length, so we pad (or trim) it to exactly 9 digits before reading it as a
+// nanosecond count.
+fn frac_to_nanos(digits: &str) -> u32 {
+    let mut nanos = String::with_capacity(9);
+    nanos.extend(digits.chars().take(9));
+    while nanos.len() < 9 {
+        nanos.push('0');
+    }
+    // We only ever keep 9 ASCII digits, so this cannot overflow a u32.
+    nanos.parse().unwrap()
+}
+
+pub fn parse_time_of_day<'a, E>(inp: &'a str) -> IResult<&'a str, Time, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
+    // `hh ':' mm` with an optional `':' ss` and optional `'.' fraction`.
+    let parser = tuple((
+        take_while_m_n(2, 2, is_digit),
+        char(':'),
+        take_while_m_n(2, 2, is_digit),
+        opt(tuple((
+            char(':'),
+            take_while_m_n(2, 2, is_digit),
+            opt(preceded(char('.'), digit1)),
+        ))),
+    ));
+
+    // Match the shape first with soft errors, so non-time inputs (a plain
+    // number, a leading `-`, …) fall through to the later `alt` branches.
+    let (i, (h, _, m, rest)) = parser(inp)?;
+
+    // Widths are fixed above, so these parses cannot fail.
+    let hour = h.parse::<u8>().unwrap();
+    let minute = m.parse::<u8>().unwrap();
+    let (second, nanos) = match rest {
+        Some((_, s, frac)) => (s.parse::<u8>().unwrap(), frac.map(frac_to_nanos).unwrap_or(0)),
+        None => (0, 0),
+    };
+
+    // Once `hh:mm` *has* matched, an out-of-range component (e.g. the leap
+    // second `60`) is a hard `Failure`, so the dispatching `alt` rejects it
+    // outright instead of falling through to `int` and swallowing the `hh`.
+    match Time::from_hms_nano(hour, minute, second, nanos) {
+        Ok(time) => Ok((i, time)),
+        Err(_) => {
+            let frag = LitParseError::InvalidDate(format!("{hour:02}:{minute:02}:{second:02}"));
+            Err(Err::Failure(E::from_external_error(
+                inp,
+                nom::error::ErrorKind::MapRes,
+                frag,
+            )))
+        }
+    }
+}
+
+pub fn parse_datetimeoffset<'a, E>(inp: &'a str) -> IResult<&'a str, OffsetDateTime, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
+    // A zone designator is either `Z` or a signed `hh:mm` offset; the sign
+    // applies to both the hour and the minute component.
+    let offset = alt((
+        value((0i8, 0i8), char('Z')),
+        map(
+            tuple((
+                one_of("+-"),
+                take_while_m_n(2, 2, is_digit),
+                char(':'),
+                take_while_m_n(2, 2, is_digit),
+            )),
+            |(sign, h, _, m): (char, &str, _, &str)| {
+                let s = if sign == '-' { -1i8 } else { 1i8 };
+                (s * h.parse::<i8>().unwrap(), s * m.parse::<i8>().unwrap())
+            },
+        ),
+    ));
+
+    // Reuse `parse_date` so OData's negative years keep working.
+    let parser = tuple((parse_date, char('T'), parse_time_of_day, offset));
+
+    map_res(parser, |(date, _, time, (oh, om))| {
+        let offset = UtcOffset::from_hms(oh, om, 0)
+            .map_err(|_| LitParseError::InvalidDate(format!("{oh:+03}:{om:02}")))?;
+        Ok::<_, LitParseError>(date.with_time(time).assume_offset(offset))
+    })(inp)
+}
+
+/// Reasons an ISO 8601 duration body can fail to make sense even once its shape
+/// has been recognized.
+#[derive(Debug)]
+pub enum DurationError {
+    /// A bare `P` or `PT` carrying no components.
+    Empty,
+    /// A component whose digit run does not fit in the backing integer.
+    Overflow,
+}
+
+impl std::fmt::Display for DurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DurationError::Empty => write!(f, "duration has no components"),
+            DurationError::Overflow => write!(f, "duration component out of range"),
+        }
+    }
+}
+
+impl std::error::Error for DurationError {}
+
+pub fn parse_duration<'a, E>(inp: &'a str) -> IResult<&'a str, time::Duration, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, DurationError>,
+{
+    // `(+|-)? 'P' (nD)? ('T' (nH)? (nM)? (n(.n)?S)? )?` — each `n` is `digit1`.
+    let body = tuple((
+        opt(one_of("+-")),
+        char('P'),
+        opt(terminated(digit1, char('D'))),
+        opt(preceded(
+            char('T'),
+            tuple((
+                opt(terminated(digit1, char('H'))),
+                opt(terminated(digit1, char('M'))),
+                opt(terminated(
+                    pair(digit1, opt(preceded(char('.'), digit1))),
+                    char('S'),
+                )),
+            )),
+        )),
+    ));
+
+    // Mirror `parse_binary`'s `duration'…'` wrapper.
+    let parser = delimited(tag_no_case("duration'"), body, char('\''));
+
+    map_res(parser, |(sign, _p, days, time)| {
+        let (hours, minutes, seconds) = time.unwrap_or((None, None, None));
+
+        // At least one component must be present; bare `P`/`PT` is invalid.
+        if days.is_none() && hours.is_none() && minutes.is_none() && seconds.is_none() {
+            return Err(DurationError::Empty);
+        }
+
+        let whole = |o: Option<&str>| -> Result<i64, DurationError> {
+            o.map_or(Ok(0), |s| s.parse::<i64>().map_err(|_| DurationError::Overflow))
+        };
+
+        let scale = |v: i64, by: i64| v.checked_mul(by).ok_or(DurationError::Overflow);
+        let add = |a: i64, b: i64| a.checked_add(b).ok_or(DurationError::Overflow);
+
+        let mut secs = scale(whole(days)?, 86_400)?;
+        secs = add(secs, scale(whole(hours)?, 3_600)?)?;
+        secs = add(secs, scale(whole(minutes)?, 60)?)?;
+
+        let mut nanos = 0;
+        if let Some((s, frac)) = seconds {
+            secs = add(secs, s.parse::<i64>().map_err(|_| DurationError::Overflow)?)?;
+            nanos = frac.map(frac_to_nanos).unwrap_or(0);
+        }
+
+        let duration = time::Duration::new(secs, nanos as i32);
+        Ok(if sign == Some('-') { -duration } else { duration })
+    })(inp)
 }
 
-pub fn parse_binary(inp: &str) -> IResult<&str, Vec<u8>> {
+pub fn parse_binary<'a, E>(inp: &'a str) -> IResult<&'a str, Vec<u8>, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, LitParseError>,
+{
     let binval = take_while(is_base64url_char);
     let parser = delimited(tag_no_case("binary'"), binval, char('\''));
 
-    // TODO: map base64::DecodeError onto a nom Error for clarity
-    map_res(parser, |b64| {
+    map_res(parser, |b64: &str| {
         // We make no assumptions about how the client handles b64 padding:
         let cfg = engine::GeneralPurposeConfig::new()
             .with_decode_padding_mode(engine::DecodePaddingMode::Indifferent);
         let engine = engine::GeneralPurpose::new(&alphabet::URL_SAFE, cfg);
-        engine.decode(b64)
+        // Report the undecodable fragment as `LitParseError::InvalidBase64`.
+        engine
+            .decode(b64)
+            .map_err(|_| LitParseError::InvalidBase64(b64.to_string()))
     })(inp)
 }
 
-pub fn parse_literal(inp: &str) -> IResult<&str, Literal> {
+fn fmt_date(f: &mut std::fmt::Formatter, date: Date) -> std::fmt::Result {
+    // OData years may be negative; keep the sign outside the zero-padded field
+    // so `parse_year` reads it back (e.g. year -1 renders as `-0001`).
+    let year = date.year();
+    if year < 0 {
+        write!(f, "-{:04}", -year)?;
+    } else {
+        write!(f, "{:04}", year)?;
+    }
+    write!(f, "-{:02}-{:02}", u8::from(date.month()), date.day())
+}
+
+fn fmt_time(f: &mut std::fmt::Formatter, time: Time) -> std::fmt::Result {
+    // Seconds are always emitted so a following fraction stays well-formed.
+    write!(f, "{:02}:{:02}:{:02}", time.hour(), time.minute(), time.second())?;
+    let nanos = time.nanosecond();
+    if nanos != 0 {
+        let frac = format!("{nanos:09}");
+        write!(f, ".{}", frac.trim_end_matches('0'))?;
+    }
+    Ok(())
+}
+
+fn fmt_offset(f: &mut std::fmt::Formatter, offset: UtcOffset) -> std::fmt::Result {
+    if offset.is_utc() {
+        return write!(f, "Z");
+    }
+    let (h, m, _) = offset.as_hms();
+    let sign = if h < 0 || m < 0 { '-' } else { '+' };
+    write!(f, "{sign}{:02}:{:02}", h.unsigned_abs(), m.unsigned_abs())
+}
+
+impl std::fmt::Display for Literal {
+    /// Renders a literal as OData query syntax that `parse_literal` accepts
+    /// back verbatim, so `parse_literal(&lit.to_string())` yields `lit`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Literal::Null => write!(f, "null"),
+            Literal::Boolean(b) => write!(f, "{b}"),
+            Literal::Integer(i) => write!(f, "{i}"),
+            Literal::Float(x) if x.is_nan() => write!(f, "NaN"),
+            Literal::Float(x) if x.is_infinite() => {
+                write!(f, "{}", if *x < 0.0 { "-INF" } else { "INF" })
+            }
+            // Exponent notation keeps the value on the `float` branch on reparse
+            // (the bare `12.0` form would instead be read as a `Decimal`).
+            Literal::Float(x) => write!(f, "{x:e}"),
+            // A zero-scale `Decimal` prints with no fraction (`12`), which
+            // would reparse as an `Integer`; force `.0` so it stays on the
+            // `parse_decimal` branch and round-trips.
+            Literal::Decimal(d) if d.scale() == 0 => write!(f, "{d}.0"),
+            Literal::Decimal(d) => write!(f, "{d}"),
+            // Invert `parse_string`: a single `'` doubles to `''`.
+            Literal::String(s) => write!(f, "'{}'", s.replace('\'', "''")),
+            Literal::GUID(g) => write!(f, "{g}"),
+            Literal::Binary(bytes) => {
+                write!(f, "binary'{}'", engine::general_purpose::URL_SAFE.encode(bytes))
+            }
+            Literal::Date(d) => fmt_date(f, *d),
+            Literal::TimeOfDay(t) => fmt_time(f, *t),
+            Literal::DateTimeOffset(dt) => {
+                fmt_date(f, dt.date())?;
+                write!(f, "T")?;
+                fmt_time(f, dt.time())?;
+                fmt_offset(f, dt.offset())
+            }
+            Literal::Duration(dur) => {
+                let negative = dur.is_negative();
+                let dur = dur.abs();
+                let secs = dur.whole_seconds();
+                let nanos = dur.subsec_nanoseconds();
+                let days = secs / 86_400;
+                let hours = (secs % 86_400) / 3_600;
+                let minutes = (secs % 3_600) / 60;
+                let seconds = secs % 60;
+
+                // The sign lives inside the quotes, immediately before `P`.
+                write!(f, "duration'{}P", if negative { "-" } else { "" })?;
+                if days != 0 {
+                    write!(f, "{days}D")?;
+                }
+                let has_time = hours != 0 || minutes != 0 || seconds != 0 || nanos != 0;
+                if has_time {
+                    write!(f, "T")?;
+                    if hours != 0 {
+                        write!(f, "{hours}H")?;
+                    }
+                    if minutes != 0 {
+                        write!(f, "{minutes}M")?;
+                    }
+                    if seconds != 0 || nanos != 0 {
+                        write!(f, "{seconds}")?;
+                        if nanos != 0 {
+                            let frac = format!("{nanos:09}");
+                            write!(f, ".{}", frac.trim_end_matches('0'))?;
+                        }
+                        write!(f, "S")?;
+                    }
+                } else if days == 0 {
+                    // A zero duration still needs at least one component.
+                    write!(f, "T0S")?;
+                }
+                write!(f, "'")
+            }
+        }
+    }
+}
+
+/// Parse a single OData literal, generic over the nom error type.
+///
+/// Pick the fast [`Error`]`<&str>` via [`parse_literal`], or a
+/// [`VerboseError`] via [`parse_literal_verbose`] to see a context stack with
+/// offsets. Each branch is wrapped in a `context` named after its literal
+/// kind. The stack names the kind being attempted only where that branch fails
+/// hard: after a `binary'` prefix, or on an out-of-range time-of-day
+/// component. When every branch fails softly (e.g. a GUID with a malformed
+/// block), `alt` keeps only the error of the last branch it tried, `duration`.
+pub fn literal<'a, E: LiteralError<'a>>(inp: &'a str) -> IResult<&'a str, Literal, E> {
     let null = value(Literal::Null, tag("null"));
 
     let bool = alt((
@@ -125,20 +502,69 @@ pub fn parse_literal(inp: &str) -> IResult<&str, Literal> {
     ));
 
     let int = map(nom::character::complete::i64, Literal::Integer);
-    let float = alt((
-        map(parse_float, Literal::Float),
-        value(Literal::Float(f64::NAN), tag("NaN")),
-        value(Literal::Float(f64::INFINITY), tag("INF")),
-        value(Literal::Float(f64::NEG_INFINITY), tag("-INF")),
-    ));
-
-    let string = map(parse_string, Literal::String);
-    let guid = map(parse_guid, Literal::GUID);
-    let binary = map(parse_binary, Literal::Binary);
+    // `decimal` is tried ahead of `float` so fixed-point inputs such as
+    // `12.340` keep their exact scale; `float` is left for exponent forms and
+    // the `NaN`/`INF`/`-INF` tokens.
+    let decimal = context("decimal", map(parse_decimal, Literal::Decimal));
+    let float = context(
+        "float",
+        alt((
+            map(parse_float, Literal::Float),
+            value(Literal::Float(f64::NAN), tag("NaN")),
+            value(Literal::Float(f64::INFINITY), tag("INF")),
+            value(Literal::Float(f64::NEG_INFINITY), tag("-INF")),
+        )),
+    );
+
+    let string = context("string", map(parse_string, Literal::String));
+    let guid = context("guid", map(parse_guid, Literal::GUID));
+    // Once the `binary'` prefix has matched, a later failure is a hard one, so
+    // the verbose stack keeps the `binary` context instead of `alt` moving on.
+    let binary = context(
+        "binary",
+        preceded(peek(tag_no_case("binary'")), cut(map(parse_binary, Literal::Binary))),
+    );
+    let duration = context("duration", map(parse_duration, Literal::Duration));
+
+    let date = context("date", map(parse_date, Literal::Date));
+    // `datetimeoffset` must precede `date`, which would otherwise happily match
+    // the leading date and leave the `T…` tail unparsed. Likewise `time_of_day`
+    // precedes `int`, whose `i64` would swallow the leading `hh`.
+    let datetimeoffset = context("datetimeoffset", map(parse_datetimeoffset, Literal::DateTimeOffset));
+    let time_of_day = context("timeOfDay", map(parse_time_of_day, Literal::TimeOfDay));
+
+    alt((
+        null,
+        bool,
+        string,
+        datetimeoffset,
+        date,
+        time_of_day,
+        guid,
+        decimal,
+        float,
+        int,
+        binary,
+        duration,
+    ))(inp)
+}
 
-    let date = map(parse_date, Literal::Date);
+/// Parse a single OData literal with nom's fast [`Error`]`<&str>`, which
+/// records no context stack.
+pub fn parse_literal(inp: &str) -> IResult<&str, Literal> {
+    literal::<Error<&str>>(inp)
+}
 
-    alt((null, bool, string, date, guid, float, int, binary))(inp)
+/// Parse a single OData literal, returning a human-readable error that records
+/// the context stack and offset on failure rather than a bare `Alt` kind. See
+/// [`literal`] for which failures name the literal kind that was attempted.
+pub fn parse_literal_verbose(inp: &str) -> Result<Literal, String> {
+    match literal::<VerboseError<&str>>(inp) {
+        Ok(("", lit)) => Ok(lit),
+        Ok((rest, _)) => Err(format!("trailing input after literal: {rest:?}")),
+        Err(Err::Error(e)) | Err(Err::Failure(e)) => Err(convert_error(inp, e)),
+        Err(Err::Incomplete(_)) => Err("incomplete input".to_string()),
+    }
 }
 
 #[cfg(test)]
@@ -181,10 +607,25 @@ mod tests {
         assert_parsed_to(parse_literal("-123456789"), Literal::Integer(-123456789));
     }
 
+    #[test]
+    fn parse_decimal() {
+        assert_parsed_to(
+            parse_literal("0.1"),
+            Literal::Decimal(Decimal::from_str_exact("0.1").unwrap()),
+        );
+        assert_parsed_to(
+            parse_literal("-0.1"),
+            Literal::Decimal(Decimal::from_str_exact("-0.1").unwrap()),
+        );
+        // Trailing zeros (scale) are preserved exactly.
+        assert_parsed_to(
+            parse_literal("12.340"),
+            Literal::Decimal(Decimal::from_str_exact("12.340").unwrap()),
+        );
+    }
+
     #[test]
     fn parse_float() {
-        assert_parsed_to(parse_literal("0.1"), Literal::Float(0.1));
-        assert_parsed_to(parse_literal("-0.1"), Literal::Float(-0.1));
         assert_parsed_to(parse_literal("1e10"), Literal::Float(1e10));
         assert_parsed_to(parse_literal("-1e10"), Literal::Float(-1e10));
         assert_parsed_to(parse_literal("1e-10"), Literal::Float(1e-10));
@@ -235,6 +676,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_time_of_day() {
+        use time::Time;
+
+        assert_parsed_to(
+            parse_literal("09:30"),
+            Literal::TimeOfDay(Time::from_hms(9, 30, 0).unwrap()),
+        );
+        assert_parsed_to(
+            parse_literal("09:30:15"),
+            Literal::TimeOfDay(Time::from_hms(9, 30, 15).unwrap()),
+        );
+        assert_parsed_to(
+            parse_literal("09:30:15.5"),
+            Literal::TimeOfDay(Time::from_hms_nano(9, 30, 15, 500_000_000).unwrap()),
+        );
+        // Leap seconds are out of range for OData.
+        assert!(parse_literal("09:30:60").is_err());
+    }
+
+    #[test]
+    fn parse_datetimeoffset() {
+        use time::macros::datetime;
+
+        assert_parsed_to(
+            parse_literal("2023-01-01T09:30:00Z"),
+            Literal::DateTimeOffset(datetime!(2023-01-01 09:30:00 UTC)),
+        );
+        assert_parsed_to(
+            parse_literal("2023-01-01T09:30:00+05:30"),
+            Literal::DateTimeOffset(datetime!(2023-01-01 09:30:00 +5:30)),
+        );
+        assert_parsed_to(
+            parse_literal("2023-01-01T09:30:00-05:00"),
+            Literal::DateTimeOffset(datetime!(2023-01-01 09:30:00 -5:00)),
+        );
+    }
+
+    #[test]
+    fn parse_duration() {
+        use time::Duration;
+
+        assert_parsed_to(
+            parse_literal("duration'P3DT4H59M'"),
+            Literal::Duration(Duration::days(3) + Duration::hours(4) + Duration::minutes(59)),
+        );
+        assert_parsed_to(
+            parse_literal("duration'PT1.5S'"),
+            Literal::Duration(Duration::new(1, 500_000_000)),
+        );
+        assert_parsed_to(
+            parse_literal("duration'-PT30S'"),
+            Literal::Duration(-Duration::seconds(30)),
+        );
+
+        // A bare `P`/`PT` with no components is invalid.
+        assert!(parse_literal("duration'P'").is_err());
+        assert!(parse_literal("duration'PT'").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        use time::macros::datetime;
+        use time::{Duration, Time};
+
+        let cases = vec![
+            Literal::Null,
+            Literal::Boolean(true),
+            Literal::Boolean(false),
+            Literal::Integer(-42),
+            Literal::Float(1e10),
+            Literal::Float(f64::INFINITY),
+            Literal::Float(f64::NEG_INFINITY),
+            Literal::Decimal(Decimal::from_str_exact("12.340").unwrap()),
+            // A zero-scale decimal must not collapse to an integer on reparse.
+            Literal::Decimal(Decimal::new(12, 0)),
+            Literal::String("g'day sir".to_string()),
+            Literal::GUID("d13efbec-aa20-47f4-8756-c38852488b6e".to_string()),
+            Literal::Binary(b"Definitely not a virus".to_vec()),
+            Literal::Date(Date::from_calendar_date(-1, Month::January, 1).unwrap()),
+            Literal::TimeOfDay(Time::from_hms_nano(9, 30, 15, 500_000_000).unwrap()),
+            Literal::DateTimeOffset(datetime!(2023-01-01 09:30:00 +5:30)),
+            Literal::Duration(Duration::days(3) + Duration::hours(4) + Duration::minutes(59)),
+            Literal::Duration(-Duration::new(1, 500_000_000)),
+            Literal::Duration(Duration::ZERO),
+        ];
+
+        for lit in cases {
+            let rendered = lit.to_string();
+            assert_parsed_to(parse_literal(&rendered), lit);
+        }
+
+        // NaN never compares equal, so check the shape separately.
+        match parse_literal(&Literal::Float(f64::NAN).to_string()) {
+            Ok(("", Literal::Float(nan))) => assert!(nan.is_nan()),
+            other => panic!("NaN did not round-trip: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn verbose_error_reports_context() {
+        // A malformed GUID block: the fast path still just fails…
+        assert!(parse_literal("d13efbec-aa20-47f4-8756-XXXXXXXXXXXX").is_err());
+
+        // …while the verbose wrapper records the literal kind it was attempting
+        // along with the offset, rather than a bare `Alt`.
+        let err = parse_literal_verbose("binary'not valid base64!'").unwrap_err();
+        assert!(err.contains("in binary"), "{err}");
+
+        // A well-formed literal still parses through the verbose path.
+        assert_eq!(
+            parse_literal_verbose("-123456789"),
+            Ok(Literal::Integer(-123456789)),
+        );
+    }
+
     #[test]
     fn parse_binary() {
         let data = b"Definitely not a virus";